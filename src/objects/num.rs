@@ -4,7 +4,7 @@
 
 extern crate num_traits;
 
-use self::num_traits::cast::cast;
+use self::num_traits::cast::{cast, NumCast};
 use std::os::raw::{c_long, c_double};
 
 use ::{Py, PyPtr};
@@ -47,6 +47,52 @@ impl PyFloat {
     pub fn value(&self) -> c_double {
         unsafe { ffi::PyFloat_AsDouble(self.as_ptr()) }
     }
+
+    /// Converts this float to an integer type, without the implicit
+    /// truncation that `extract::<T>()` performs on a float (through
+    /// `PyLong_AsLong` or `PyNumber_Long`, depending on `T`).
+    ///
+    /// Returns an error unless `self` is finite, has no fractional part,
+    /// and fits within the range of `T`.
+    pub fn try_into_exact_int<T: NumCast>(&self) -> PyResult<T> {
+        let py = self.token();
+        let value = self.value();
+        if !value.is_finite() || value.fract() != 0.0 {
+            return Err(PyErr::new_lazy_init(py.get_type::<exc::ValueError>(), None));
+        }
+        match cast::<c_double, T>(value) {
+            Some(v) => Ok(v),
+            None => Err(overflow_error(py)),
+        }
+    }
+}
+
+/// Represents a Python `complex` object.
+///
+/// You can usually avoid directly working with this type
+/// by using [ToPyObject](trait.ToPyObject.html)
+/// and [extract](struct.PyObject.html#method.extract)
+/// with `num_complex::Complex`.
+pub struct PyComplex(PythonToken<PyComplex>);
+pyobject_newtype!(PyComplex, PyComplex_Check, PyComplex_Type);
+
+impl PyComplex {
+    /// Creates a new Python `complex` object.
+    pub fn new(_py: Token, real: c_double, imag: c_double) -> PyPtr<PyComplex> {
+        unsafe {
+            PyPtr::from_owned_ptr_or_panic(ffi::PyComplex_FromDoubles(real, imag))
+        }
+    }
+
+    /// Gets the real part of this complex number.
+    pub fn real(&self) -> c_double {
+        unsafe { ffi::PyComplex_RealAsDouble(self.as_ptr()) }
+    }
+
+    /// Gets the imaginary part of this complex number.
+    pub fn imag(&self) -> c_double {
+        unsafe { ffi::PyComplex_ImagAsDouble(self.as_ptr()) }
+    }
 }
 
 
@@ -168,6 +214,160 @@ int_fits_larger_int!(usize, u64);
 // u64 has a manual implementation as it never fits into signed long
 int_convert_u64_or_i64!(u64, ffi::PyLong_FromUnsignedLongLong, ffi::PyLong_AsUnsignedLongLong);
 
+// `PyNumber_Long` hands back a new strong reference; `PyLong_Check` leaves
+// the refcount untouched. `OwnedPyLong` normalizes both into a single
+// owned reference so extraction code can use it without leaking.
+struct OwnedPyLong(*mut ffi::PyObject);
+
+impl OwnedPyLong {
+    unsafe fn from_ptr(py: Token, ptr: *mut ffi::PyObject) -> PyResult<OwnedPyLong> {
+        if ffi::PyLong_Check(ptr) != 0 {
+            ffi::Py_INCREF(ptr);
+            Ok(OwnedPyLong(ptr))
+        } else {
+            let num = ffi::PyNumber_Long(ptr);
+            if num.is_null() {
+                Err(PyErr::fetch(py))
+            } else {
+                Ok(OwnedPyLong(num))
+            }
+        }
+    }
+}
+
+impl Drop for OwnedPyLong {
+    fn drop(&mut self) {
+        unsafe { ffi::Py_DECREF(self.0); }
+    }
+}
+
+macro_rules! int_convert_128 (
+    ($rust_type:ty, $is_signed:expr) => (
+        impl ToPyObject for $rust_type {
+            fn to_object(&self, py: Token) -> PyPtr<PyObject> {
+                let bytes = self.to_le_bytes();
+                unsafe {
+                    PyPtr::from_owned_ptr_or_panic(
+                        ffi::_PyLong_FromByteArray(bytes.as_ptr(), bytes.len(), 1, $is_signed))
+                }
+            }
+        }
+
+        impl<'source> FromPyObject<'source> for $rust_type {
+            fn extract<S>(py: &'source Py<'source, S>) -> PyResult<$rust_type>
+                where S: PyTypeInfo
+            {
+                unsafe {
+                    let num = try!(OwnedPyLong::from_ptr(py.token(), py.as_ptr()));
+                    let mut buffer = [0u8; 16];
+                    let result = ffi::_PyLong_AsByteArray(
+                        num.0 as *mut ffi::PyLongObject, buffer.as_mut_ptr(), buffer.len(), 1, $is_signed);
+                    if result == -1 && PyErr::occurred(py.token()) {
+                        Err(PyErr::fetch(py.token()))
+                    } else {
+                        Ok(<$rust_type>::from_le_bytes(buffer))
+                    }
+                }
+            }
+        }
+    )
+);
+
+// i128/u128 always go through the byte-array FFI, since no c_long-family
+// function handles more than 64 bits.
+int_convert_128!(i128, 1);
+int_convert_128!(u128, 0);
+
+#[cfg(feature="num-bigint")]
+mod bigint {
+    extern crate num_bigint;
+
+    use self::num_bigint::{BigInt, BigUint, Sign};
+    use ffi;
+    use ::{Py, PyPtr};
+    use super::{PyObject, OwnedPyLong};
+    use typeob::PyTypeInfo;
+    use python::{ToPythonPointer, PythonObjectWithToken, Token};
+    use err::{PyResult, PyErr};
+    use conversion::{ToPyObject, FromPyObject};
+
+    // Two's-complement encode a magnitude so it round-trips through
+    // `_PyLong_FromByteArray`/`_PyLong_AsByteArray` with `is_signed = 1`.
+    fn signed_bytes(sign: Sign, mut magnitude: Vec<u8>) -> Vec<u8> {
+        if magnitude.last().map_or(true, |&b| b & 0x80 != 0) {
+            magnitude.push(0);
+        }
+        if sign == Sign::Minus {
+            let mut carry = 1u16;
+            for b in magnitude.iter_mut() {
+                let v = (!*b) as u16 + carry;
+                *b = v as u8;
+                carry = v >> 8;
+            }
+        }
+        magnitude
+    }
+
+    impl ToPyObject for BigUint {
+        fn to_object(&self, py: Token) -> PyPtr<PyObject> {
+            let bytes = self.to_bytes_le();
+            unsafe {
+                PyPtr::from_owned_ptr_or_panic(
+                    ffi::_PyLong_FromByteArray(bytes.as_ptr(), bytes.len(), 1, 0))
+            }
+        }
+    }
+
+    impl ToPyObject for BigInt {
+        fn to_object(&self, py: Token) -> PyPtr<PyObject> {
+            let (sign, magnitude) = self.to_bytes_le();
+            let bytes = signed_bytes(sign, magnitude);
+            unsafe {
+                PyPtr::from_owned_ptr_or_panic(
+                    ffi::_PyLong_FromByteArray(bytes.as_ptr(), bytes.len(), 1, 1))
+            }
+        }
+    }
+
+    impl<'source> FromPyObject<'source> for BigUint {
+        fn extract<S>(py: &'source Py<'source, S>) -> PyResult<BigUint>
+            where S: PyTypeInfo
+        {
+            unsafe {
+                let num = try!(OwnedPyLong::from_ptr(py.token(), py.as_ptr()));
+                let nbits = ffi::_PyLong_NumBits(num.0);
+                let mut buffer = vec![0u8; nbits / 8 + 1];
+                let result = ffi::_PyLong_AsByteArray(
+                    num.0 as *mut ffi::PyLongObject, buffer.as_mut_ptr(), buffer.len(), 1, 0);
+                if result == -1 && PyErr::occurred(py.token()) {
+                    Err(PyErr::fetch(py.token()))
+                } else {
+                    Ok(BigUint::from_bytes_le(&buffer))
+                }
+            }
+        }
+    }
+
+    impl<'source> FromPyObject<'source> for BigInt {
+        fn extract<S>(py: &'source Py<'source, S>) -> PyResult<BigInt>
+            where S: PyTypeInfo
+        {
+            unsafe {
+                let num = try!(OwnedPyLong::from_ptr(py.token(), py.as_ptr()));
+                let nbits = ffi::_PyLong_NumBits(num.0);
+                let mut buffer = vec![0u8; nbits / 8 + 1];
+                let result = ffi::_PyLong_AsByteArray(
+                    num.0 as *mut ffi::PyLongObject, buffer.as_mut_ptr(), buffer.len(), 1, 1);
+                if result == -1 && PyErr::occurred(py.token()) {
+                    Err(PyErr::fetch(py.token()))
+                } else {
+                    Ok(BigInt::from_signed_bytes_le(&buffer))
+                }
+            }
+        }
+    }
+}
+
 impl ToPyObject for f64 {
     fn to_object(&self, py: Token) -> PyPtr<PyObject> {
         PyFloat::new(py, *self).into_object()
@@ -197,11 +397,59 @@ pyobject_extract!(obj to f32 => {
     Ok(try!(obj.extract::<f64>()) as f32)
 });
 
+#[cfg(feature="num-complex")]
+mod complex {
+    extern crate num_complex;
+
+    use self::num_complex::Complex;
+    use ::PyPtr;
+    use super::{PyObject, PyComplex};
+    use typeob::PyTypeInfo;
+    use python::{Py, Token, PythonObjectWithToken};
+    use err::{PyResult, PyErr};
+    use conversion::{ToPyObject, FromPyObject};
+
+    impl ToPyObject for Complex<f64> {
+        fn to_object(&self, py: Token) -> PyPtr<PyObject> {
+            PyComplex::new(py, self.re, self.im).into_object()
+        }
+    }
+
+    impl<'source> FromPyObject<'source> for Complex<f64> {
+        fn extract<S>(py: &'source Py<'source, S>) -> PyResult<Complex<f64>>
+            where S: PyTypeInfo
+        {
+            let ptr = py.as_ptr();
+            let re = unsafe { ::ffi::PyComplex_RealAsDouble(ptr) };
+            if re == -1.0 && PyErr::occurred(py.token()) {
+                return Err(PyErr::fetch(py.token()));
+            }
+            let im = unsafe { ::ffi::PyComplex_ImagAsDouble(ptr) };
+            if im == -1.0 && PyErr::occurred(py.token()) {
+                return Err(PyErr::fetch(py.token()));
+            }
+            Ok(Complex::new(re, im))
+        }
+    }
+
+    impl ToPyObject for Complex<f32> {
+        fn to_object(&self, py: Token) -> PyPtr<PyObject> {
+            Complex::new(self.re as f64, self.im as f64).to_object(py)
+        }
+    }
+
+    pyobject_extract!(obj to Complex<f32> => {
+        let c = try!(obj.extract::<Complex<f64>>());
+        Ok(Complex::new(c.re as f32, c.im as f32))
+    });
+}
+
 #[cfg(test)]
 mod test {
     use std;
     use python::Python;
     use conversion::ToPyObject;
+    use super::PyFloat;
 
     macro_rules! num_to_py_object_and_back (
         ($func_name:ident, $t1:ty, $t2:ty) => (
@@ -228,6 +476,8 @@ mod test {
     num_to_py_object_and_back!(to_from_u64, u64, u64);
     num_to_py_object_and_back!(to_from_isize, isize, isize);
     num_to_py_object_and_back!(to_from_usize, usize, usize);
+    num_to_py_object_and_back!(to_from_i128, i128, i128);
+    num_to_py_object_and_back!(to_from_u128, u128, u128);
     num_to_py_object_and_back!(float_to_i32, f64, i32);
     num_to_py_object_and_back!(float_to_u32, f64, u32);
     num_to_py_object_and_back!(float_to_i64, f64, i64);
@@ -277,4 +527,121 @@ mod test {
         assert_eq!(v, obj.extract::<u64>(py).unwrap());
         assert!(obj.extract::<i64>(py).is_err());
     }
+
+    #[test]
+    fn test_i128_max() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let v = std::i128::MAX;
+        let obj = v.to_py_object(py).into_object();
+        assert_eq!(v, obj.extract::<i128>(py).unwrap());
+        assert!(obj.extract::<u64>(py).is_err());
+    }
+
+    #[test]
+    fn test_i128_min() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let v = std::i128::MIN;
+        let obj = v.to_py_object(py).into_object();
+        assert_eq!(v, obj.extract::<i128>(py).unwrap());
+        assert!(obj.extract::<i64>(py).is_err());
+    }
+
+    #[test]
+    fn test_u128_max() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let v = std::u128::MAX;
+        let obj = v.to_py_object(py).into_object();
+        assert_eq!(v, obj.extract::<u128>(py).unwrap());
+        assert!(obj.extract::<i128>(py).is_err());
+    }
+
+    #[test]
+    fn try_into_exact_int_integral() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let f = PyFloat::new(py, 3.0);
+        assert_eq!(f.try_into_exact_int::<i32>().unwrap(), 3);
+    }
+
+    #[test]
+    fn try_into_exact_int_rejects_fraction() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let f = PyFloat::new(py, 3.7);
+        assert!(f.try_into_exact_int::<i32>().is_err());
+    }
+
+    #[test]
+    fn try_into_exact_int_rejects_out_of_range() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let f = PyFloat::new(py, std::u64::MAX as f64 * 2.0);
+        assert!(f.try_into_exact_int::<i32>().is_err());
+    }
+}
+
+#[cfg(all(test, feature="num-bigint"))]
+mod bigint_test {
+    extern crate num_bigint;
+
+    use self::num_bigint::{BigInt, BigUint};
+    use python::Python;
+    use conversion::ToPyObject;
+
+    #[test]
+    fn biguint_roundtrip() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let v: BigUint = BigUint::parse_bytes(b"123456789012345678901234567890", 10).unwrap();
+        let obj = v.to_object(py).into_object();
+        assert_eq!(v, obj.extract::<BigUint>(py).unwrap());
+    }
+
+    #[test]
+    fn bigint_roundtrip_negative() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let v: BigInt = BigInt::parse_bytes(b"-123456789012345678901234567890", 10).unwrap();
+        let obj = v.to_object(py).into_object();
+        assert_eq!(v, obj.extract::<BigInt>(py).unwrap());
+    }
+
+    #[test]
+    fn bigint_roundtrip_small() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let v: BigInt = BigInt::from(-42);
+        let obj = v.to_object(py).into_object();
+        assert_eq!(v, obj.extract::<BigInt>(py).unwrap());
+    }
+}
+
+#[cfg(all(test, feature="num-complex"))]
+mod complex_test {
+    extern crate num_complex;
+
+    use self::num_complex::Complex;
+    use python::Python;
+    use conversion::ToPyObject;
+
+    #[test]
+    fn to_from_complex_f64() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let val = Complex::new(1.25, -2.5);
+        let obj = val.to_object(py).into_object();
+        assert_eq!(val, obj.extract::<Complex<f64>>(py).unwrap());
+    }
+
+    #[test]
+    fn to_from_complex_f32() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let val = Complex::new(1.25f32, -2.5f32);
+        let obj = val.to_object(py).into_object();
+        assert_eq!(val, obj.extract::<Complex<f32>>(py).unwrap());
+    }
 }